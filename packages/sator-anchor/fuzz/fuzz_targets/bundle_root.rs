@@ -0,0 +1,51 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use sator_anchor::hashing::{domain, domain_hash};
+use sator_anchor::state::{merkle_parent, merkle_root, merkle_root_from_proof, IncidentAnchor};
+
+#[derive(Arbitrary, Debug)]
+struct BundleRootInput {
+    leaves: [[u8; 32]; IncidentAnchor::MERKLE_LEAF_COUNT],
+    leaf_index: u8,
+    garbage_proof: [[u8; 32]; IncidentAnchor::MERKLE_DEPTH],
+}
+
+fuzz_target!(|input: BundleRootInput| {
+    // Building the tree from arbitrary leaves must never panic or overflow
+    let root = merkle_root(input.leaves);
+
+    // A proof assembled from the real siblings of a given leaf must always recompute
+    // back to the tree's root, for every leaf position
+    let index = input.leaf_index as usize % IncidentAnchor::MERKLE_LEAF_COUNT;
+    let mut real_proof = Vec::with_capacity(IncidentAnchor::MERKLE_DEPTH);
+    let mut level = input.leaves.to_vec();
+    let mut pos = index;
+    while level.len() > 1 {
+        real_proof.push(level[pos ^ 1]);
+        level = level
+            .chunks(2)
+            .map(|pair| merkle_parent(&pair[0], &pair[1]))
+            .collect();
+        pos /= 2;
+    }
+    let recomputed = merkle_root_from_proof(index as u8, input.leaves[index], &real_proof);
+    assert_eq!(
+        recomputed, root,
+        "a valid inclusion proof failed to reproduce the bundle root"
+    );
+
+    // An arbitrary (likely bogus) proof must never panic even when it doesn't match
+    let _ = merkle_root_from_proof(input.leaf_index, input.leaves[0], &input.garbage_proof);
+
+    // Domain separation: the same payload hashed under two distinct artifact domains
+    // must never collide
+    if input.leaves[0] != input.leaves[1] || input.leaves[0] != [0u8; 32] {
+        assert_ne!(
+            domain_hash(domain::EVIDENCE_SET, &input.leaves[0]),
+            domain_hash(domain::TIMELINE, &input.leaves[0]),
+            "distinct artifact domains collided on the same payload"
+        );
+    }
+});
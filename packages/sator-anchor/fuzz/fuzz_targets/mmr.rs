@@ -0,0 +1,46 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use sator_anchor::state::{mmr_append, mmr_bag_peaks, IncidentAnchor};
+
+#[derive(Arbitrary, Debug)]
+struct MmrInput {
+    leaves: Vec<[u8; 32]>,
+}
+
+fuzz_target!(|input: MmrInput| {
+    let mut peaks = [[0u8; 32]; IncidentAnchor::MAX_MMR_PEAKS];
+    let mut peak_count = 0u8;
+    let mut event_count: u32 = 0;
+
+    for leaf in input.leaves.iter().take(10_000) {
+        // Mirrors the checked_add guard in append_event/update_artifacts: never drive
+        // event_count past u32::MAX
+        if event_count == u32::MAX {
+            break;
+        }
+
+        let (new_peaks, new_peak_count) = mmr_append(&peaks, peak_count, event_count, *leaf);
+        peaks = new_peaks;
+        peak_count = new_peak_count;
+        event_count += 1;
+
+        // event_count is a u32, so it can have at most 32 set bits, i.e. at most 32 peaks
+        assert!(
+            peak_count as usize <= IncidentAnchor::MAX_MMR_PEAKS,
+            "peak count exceeded the fixed-size peaks array"
+        );
+
+        // Bagging the current peaks must never panic
+        let _ = mmr_bag_peaks(&peaks, peak_count);
+    }
+
+    // peak_count must always equal the number of set bits in event_count: that's the
+    // core MMR invariant this module relies on to avoid storing explicit heights
+    assert_eq!(
+        peak_count as u32,
+        event_count.count_ones(),
+        "peak count diverged from the set bits of event_count"
+    );
+});
@@ -28,4 +28,46 @@ pub enum SatorAnchorError {
     
     #[msg("Invalid hash provided")]
     InvalidHash,
+
+    #[msg("Proof length does not match the Merkle tree depth")]
+    InvalidProofLength,
+
+    #[msg("Inclusion proof does not match the bundle root")]
+    InclusionProofMismatch,
+
+    #[msg("Too many approvers for the committee")]
+    TooManyApprovers,
+
+    #[msg("Required approvals must be between 1 and the committee size")]
+    InvalidApprovalThreshold,
+
+    #[msg("Signer is not a member of the approval committee")]
+    ApproverNotInCommittee,
+
+    #[msg("This committee member has already approved this anchor")]
+    AlreadyApprovedByApprover,
+
+    #[msg("Approval window has expired; anchor must be recreated")]
+    ApprovalWindowExpired,
+
+    #[msg("Operator is not registered or is inactive")]
+    OperatorNotActive,
+
+    #[msg("Operator registry entry does not belong to this signer")]
+    OperatorRegistryMismatch,
+
+    #[msg("Sibling peak count or peak index does not match the MMR peaks")]
+    InvalidMmrProof,
+
+    #[msg("Event membership proof does not match the event chain head")]
+    EventMembershipProofMismatch,
+
+    #[msg("Artifact payload does not borsh-decode as its canonical structured type")]
+    PayloadDecodeFailed,
+
+    #[msg("Leaf index is out of range for the artifact leaves of the Merkle tree")]
+    InvalidLeafIndex,
+
+    #[msg("Approval window must be a positive number of seconds")]
+    InvalidApprovalWindow,
 }
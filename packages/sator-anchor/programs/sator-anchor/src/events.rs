@@ -32,11 +32,13 @@ pub struct ArtifactsUpdated {
     pub timestamp: i64,
 }
 
-/// Emitted when a supervisor approves an anchor
+/// Emitted when a committee member approves an anchor
 #[event]
 pub struct AnchorApproved {
     pub incident_id: u64,
     pub operator: Pubkey,
-    pub supervisor: Pubkey,
+    pub approver: Pubkey,
+    pub approval_count: u8,
+    pub required_approvals: u8,
     pub timestamp: i64,
 }
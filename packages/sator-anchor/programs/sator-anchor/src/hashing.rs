@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::errors::SatorAnchorError;
+
+/// Prefixed before every domain-tagged hash; bump this whenever the canonical
+/// encoding for an artifact or event changes so old and new hashes can never collide
+pub const HASH_VERSION: u8 = 1;
+
+/// Fixed domain tags, one per artifact/event kind, so a hash valid in one field
+/// (say, an evidence set) can never be replayed into another (say, a timeline)
+pub mod domain {
+    pub const INCIDENT_CORE: &[u8] = b"SATOR:incident_core:v1";
+    pub const EVIDENCE_SET: &[u8] = b"SATOR:evidence_set:v1";
+    pub const CONTRADICTIONS: &[u8] = b"SATOR:contradictions:v1";
+    pub const TRUST_RECEIPT: &[u8] = b"SATOR:trust_receipt:v1";
+    pub const OPERATOR_DECISIONS: &[u8] = b"SATOR:operator_decisions:v1";
+    pub const TIMELINE: &[u8] = b"SATOR:timeline:v1";
+    pub const EVENT: &[u8] = b"SATOR:event:v1";
+}
+
+/// Canonical structured form behind `IncidentAnchor::incident_core_hash`
+/// (title, severity, location, as described on that account field)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct IncidentCore {
+    pub title: String,
+    pub severity: u8,
+    pub location: String,
+}
+
+/// A single sensor reading or snapshot reference within an evidence set
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EvidenceItem {
+    pub description: String,
+    pub payload_hash: [u8; 32],
+    pub captured_at: i64,
+}
+
+/// Canonical structured form behind `IncidentAnchor::evidence_set_hash`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EvidenceSet {
+    pub items: Vec<EvidenceItem>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Contradiction {
+    pub description: String,
+    pub severity: u8,
+}
+
+/// Canonical structured form behind `IncidentAnchor::contradictions_hash`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Contradictions {
+    pub items: Vec<Contradiction>,
+}
+
+/// Canonical structured form behind `IncidentAnchor::trust_receipt_hash`
+/// (score, confidence, reason codes, as described on that account field)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TrustReceipt {
+    pub score: u16,
+    pub confidence: u16,
+    pub reason_codes: Vec<u16>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OperatorDecision {
+    pub operator: Pubkey,
+    pub decision: String,
+    pub timestamp: i64,
+}
+
+/// Canonical structured form behind `IncidentAnchor::operator_decisions_hash`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OperatorDecisions {
+    pub items: Vec<OperatorDecision>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TimelineEntry {
+    pub timestamp: i64,
+    pub description: String,
+}
+
+/// Canonical structured form behind `IncidentAnchor::timeline_hash`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Timeline {
+    pub entries: Vec<TimelineEntry>,
+}
+
+/// Canonical structured form behind an event chain entry (the payload behind
+/// `append_event`'s and `update_artifacts`' change-event hashes)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EventRecord {
+    pub kind: String,
+    pub data: Vec<u8>,
+    pub timestamp: i64,
+}
+
+/// Domain-separated, versioned hash: SHA-256(domain_tag || version || payload)
+pub fn domain_hash(domain_tag: &[u8], payload: &[u8]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(domain_tag.len() + 1 + payload.len());
+    data.extend_from_slice(domain_tag);
+    data.push(HASH_VERSION);
+    data.extend_from_slice(payload);
+
+    hash(&data).to_bytes()
+}
+
+/// Cross-checks a caller-claimed artifact/event hash against its canonical payload and
+/// returns the value to store on-chain. The returned value is always `claimed_hash`
+/// itself, regardless of whether `payload` was supplied, so the stored commitment for a
+/// given artifact never changes depending on an orthogonal transport decision (e.g.
+/// `create_anchor` omitting the payload and a later `update_artifacts` supplying it for
+/// the same, unchanged artifact must not silently mutate `bundle_root_hash`).
+///
+/// Instruction data is capped by Solana's ~1232-byte transaction limit, so most artifacts
+/// (e.g. an evidence set of "all sensor readings, snapshots") can't travel on-chain in
+/// full every time. When `payload` is present, it must borsh-decode as `T` — rejecting
+/// bytes that don't conform to the artifact's canonical schema, so two clients can never
+/// disagree on how to serialize the same data — and the domain-separated hash of its
+/// canonical re-encoding must equal `claimed_hash`. When `payload` is absent there is
+/// nothing to recompute from, so `claimed_hash` is trusted as-is.
+pub fn cross_check<T: AnchorSerialize + AnchorDeserialize>(
+    domain_tag: &[u8],
+    claimed_hash: [u8; 32],
+    payload: Option<&[u8]>,
+) -> Result<[u8; 32]> {
+    if let Some(bytes) = payload {
+        let artifact = T::try_from_slice(bytes)
+            .map_err(|_| error!(SatorAnchorError::PayloadDecodeFailed))?;
+        let canonical = artifact
+            .try_to_vec()
+            .expect("borsh serialization into a Vec is infallible");
+        require!(
+            domain_hash(domain_tag, &canonical) == claimed_hash,
+            SatorAnchorError::InvalidHash
+        );
+    }
+    Ok(claimed_hash)
+}
+
+pub fn hash_incident_core(artifact: &IncidentCore) -> [u8; 32] {
+    domain_hash(
+        domain::INCIDENT_CORE,
+        &artifact.try_to_vec().expect("borsh serialization into a Vec is infallible"),
+    )
+}
+
+pub fn hash_evidence_set(artifact: &EvidenceSet) -> [u8; 32] {
+    domain_hash(
+        domain::EVIDENCE_SET,
+        &artifact.try_to_vec().expect("borsh serialization into a Vec is infallible"),
+    )
+}
+
+pub fn hash_contradictions(artifact: &Contradictions) -> [u8; 32] {
+    domain_hash(
+        domain::CONTRADICTIONS,
+        &artifact.try_to_vec().expect("borsh serialization into a Vec is infallible"),
+    )
+}
+
+pub fn hash_trust_receipt(artifact: &TrustReceipt) -> [u8; 32] {
+    domain_hash(
+        domain::TRUST_RECEIPT,
+        &artifact.try_to_vec().expect("borsh serialization into a Vec is infallible"),
+    )
+}
+
+pub fn hash_operator_decisions(artifact: &OperatorDecisions) -> [u8; 32] {
+    domain_hash(
+        domain::OPERATOR_DECISIONS,
+        &artifact.try_to_vec().expect("borsh serialization into a Vec is infallible"),
+    )
+}
+
+pub fn hash_timeline(artifact: &Timeline) -> [u8; 32] {
+    domain_hash(
+        domain::TIMELINE,
+        &artifact.try_to_vec().expect("borsh serialization into a Vec is infallible"),
+    )
+}
+
+pub fn hash_event(artifact: &EventRecord) -> [u8; 32] {
+    domain_hash(
+        domain::EVENT,
+        &artifact.try_to_vec().expect("borsh serialization into a Vec is infallible"),
+    )
+}
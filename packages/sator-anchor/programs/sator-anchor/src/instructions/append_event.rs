@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::IncidentAnchor;
+use crate::state::{IncidentAnchor, OperatorRegistry};
 use crate::errors::SatorAnchorError;
 use crate::events::EventAppended;
+use crate::hashing;
 
 #[derive(Accounts)]
 pub struct AppendEvent<'info> {
@@ -12,25 +13,44 @@ pub struct AppendEvent<'info> {
         has_one = operator @ SatorAnchorError::Unauthorized
     )]
     pub anchor: Account<'info, IncidentAnchor>,
-    
+
+    #[account(
+        seeds = [b"operator", operator.key().as_ref()],
+        bump = operator_registry.bump,
+        has_one = operator @ SatorAnchorError::OperatorRegistryMismatch,
+    )]
+    pub operator_registry: Account<'info, OperatorRegistry>,
+
     pub operator: Signer<'info>,
 }
 
 pub fn handler(
     ctx: Context<AppendEvent>,
     event_hash: [u8; 32],
+    event_payload: Option<Vec<u8>>,
 ) -> Result<()> {
+    require!(
+        ctx.accounts.operator_registry.active,
+        SatorAnchorError::OperatorNotActive
+    );
+
     let anchor = &mut ctx.accounts.anchor;
     let clock = Clock::get()?;
-    
-    // Compute new event chain head
-    let new_head = anchor.compute_new_event_head(&event_hash);
-    
-    // Update anchor
-    anchor.event_chain_head = new_head;
-    anchor.event_count = anchor.event_count
+
+    let new_event_count = anchor.event_count
         .checked_add(1)
         .ok_or(SatorAnchorError::EventCountOverflow)?;
+
+    // When the backend also sends the canonical payload, cross-check the claimed hash
+    // against a fresh domain-separated recomputation; otherwise trust the claimed hash
+    let event_hash = hashing::cross_check::<hashing::EventRecord>(
+        hashing::domain::EVENT,
+        event_hash,
+        event_payload.as_deref(),
+    )?;
+    let new_head = anchor.append_mmr_leaf(event_hash);
+    anchor.event_chain_head = new_head;
+    anchor.event_count = new_event_count;
     anchor.updated_at = clock.unix_timestamp;
     
     // Emit event
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::IncidentAnchor;
+use crate::state::{IncidentAnchor, OperatorRegistry, OperatorRole};
 use crate::errors::SatorAnchorError;
 use crate::events::AnchorApproved;
 
@@ -11,43 +11,86 @@ pub struct ApproveAnchor<'info> {
         bump = anchor.bump,
     )]
     pub anchor: Account<'info, IncidentAnchor>,
-    
+
+    #[account(
+        seeds = [b"operator", approver.key().as_ref()],
+        bump = approver_registry.bump,
+        constraint = approver_registry.operator == approver.key() @ SatorAnchorError::OperatorRegistryMismatch,
+    )]
+    pub approver_registry: Account<'info, OperatorRegistry>,
+
     /// The supervisor or admin approving this anchor
     pub approver: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<ApproveAnchor>) -> Result<()> {
+    // Role comes from the verified on-chain registry, not a trusted caller
+    require!(
+        ctx.accounts.approver_registry.active,
+        SatorAnchorError::OperatorNotActive
+    );
+    require!(
+        matches!(
+            ctx.accounts.approver_registry.role,
+            OperatorRole::Supervisor | OperatorRole::Admin
+        ),
+        SatorAnchorError::NotSupervisorOrAdmin
+    );
+
     let anchor = &mut ctx.accounts.anchor;
     let clock = Clock::get()?;
-    
+
     // Check if already approved
     require!(
         anchor.requires_approval,
         SatorAnchorError::AlreadyApproved
     );
-    
-    // In a real implementation, we'd verify the approver has supervisor/admin role
-    // For now, we trust the caller (backend enforces role checks)
-    
-    // Mark as approved
-    anchor.supervisor = Some(ctx.accounts.approver.key());
-    anchor.requires_approval = false;
-    anchor.approval_timestamp = Some(clock.unix_timestamp);
+
+    // Stale anchors must be recreated rather than rubber-stamped later
+    require!(
+        clock.unix_timestamp - anchor.created_at <= anchor.approval_window_seconds,
+        SatorAnchorError::ApprovalWindowExpired
+    );
+
+    let approver_key = ctx.accounts.approver.key();
+    let approver_index = anchor.approvers[..anchor.approver_count as usize]
+        .iter()
+        .position(|a| *a == approver_key)
+        .ok_or(SatorAnchorError::ApproverNotInCommittee)?;
+
+    let approver_bit = 1u64 << approver_index;
+    require!(
+        anchor.approval_bitmask & approver_bit == 0,
+        SatorAnchorError::AlreadyApprovedByApprover
+    );
+
+    // Mark this committee member's bit and count how many have approved so far
+    anchor.approval_bitmask |= approver_bit;
+    let approval_count = anchor.approval_bitmask.count_ones() as u8;
     anchor.updated_at = clock.unix_timestamp;
-    
+
+    if approval_count >= anchor.required_approvals {
+        anchor.requires_approval = false;
+        anchor.approval_timestamp = Some(clock.unix_timestamp);
+    }
+
     // Emit event
     emit!(AnchorApproved {
         incident_id: anchor.incident_id,
         operator: anchor.operator,
-        supervisor: ctx.accounts.approver.key(),
+        approver: approver_key,
+        approval_count,
+        required_approvals: anchor.required_approvals,
         timestamp: clock.unix_timestamp,
     });
-    
+
     msg!(
-        "Anchor for incident {} approved by {:?}", 
-        anchor.incident_id, 
-        ctx.accounts.approver.key()
+        "Anchor for incident {} approved by {:?} ({}/{})",
+        anchor.incident_id,
+        approver_key,
+        approval_count,
+        anchor.required_approvals
     );
-    
+
     Ok(())
 }
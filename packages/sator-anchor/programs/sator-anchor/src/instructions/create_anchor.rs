@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::IncidentAnchor;
+use crate::state::{IncidentAnchor, OperatorRegistry, OperatorRole};
 use crate::errors::SatorAnchorError;
 use crate::events::AnchorCreated;
+use crate::hashing;
 
 #[derive(Accounts)]
 #[instruction(incident_id: u64)]
@@ -14,10 +15,17 @@ pub struct CreateAnchor<'info> {
         bump
     )]
     pub anchor: Account<'info, IncidentAnchor>,
-    
+
+    #[account(
+        seeds = [b"operator", operator.key().as_ref()],
+        bump = operator_registry.bump,
+        has_one = operator @ SatorAnchorError::OperatorRegistryMismatch,
+    )]
+    pub operator_registry: Account<'info, OperatorRegistry>,
+
     #[account(mut)]
     pub operator: Signer<'info>,
-    
+
     pub system_program: Program<'info, System>,
 }
 
@@ -31,38 +39,104 @@ pub fn handler(
     operator_decisions_hash: [u8; 32],
     timeline_hash: [u8; 32],
     initial_event_hash: [u8; 32],
-    operator_role: u8,
+    incident_core_payload: Option<Vec<u8>>,
+    evidence_set_payload: Option<Vec<u8>>,
+    contradictions_payload: Option<Vec<u8>>,
+    trust_receipt_payload: Option<Vec<u8>>,
+    operator_decisions_payload: Option<Vec<u8>>,
+    timeline_payload: Option<Vec<u8>>,
+    initial_event_payload: Option<Vec<u8>>,
     packet_uri: String,
+    approvers: Vec<Pubkey>,
+    required_approvals: u8,
+    approval_window_seconds: i64,
 ) -> Result<()> {
     // Validate URI length
     require!(
         packet_uri.len() <= IncidentAnchor::MAX_URI_LEN,
         SatorAnchorError::PacketUriTooLong
     );
-    
-    // Validate role
+
+    // Role comes from the verified on-chain registry, not a caller-supplied argument
     require!(
-        operator_role <= 2,
-        SatorAnchorError::InvalidOperatorRole
+        ctx.accounts.operator_registry.active,
+        SatorAnchorError::OperatorNotActive
     );
-    
+    let operator_role = ctx.accounts.operator_registry.role;
+
+    // Validate approval committee
+    require!(
+        approvers.len() <= IncidentAnchor::MAX_APPROVERS,
+        SatorAnchorError::TooManyApprovers
+    );
+    require!(
+        required_approvals >= 1 && required_approvals as usize <= approvers.len(),
+        SatorAnchorError::InvalidApprovalThreshold
+    );
+    require!(
+        approval_window_seconds > 0,
+        SatorAnchorError::InvalidApprovalWindow
+    );
+
     let anchor = &mut ctx.accounts.anchor;
     let clock = Clock::get()?;
-    
-    // Set all fields
+
+    // Set all fields. When the backend also sends the canonical payload, cross-check
+    // the claimed hash against a fresh domain-separated recomputation; otherwise (the
+    // common case for artifacts too large to fit in one transaction) trust the hash.
     anchor.operator = ctx.accounts.operator.key();
     anchor.incident_id = incident_id;
-    anchor.incident_core_hash = incident_core_hash;
-    anchor.evidence_set_hash = evidence_set_hash;
-    anchor.contradictions_hash = contradictions_hash;
-    anchor.trust_receipt_hash = trust_receipt_hash;
-    anchor.operator_decisions_hash = operator_decisions_hash;
-    anchor.timeline_hash = timeline_hash;
-    anchor.event_chain_head = initial_event_hash;
+    anchor.incident_core_hash = hashing::cross_check::<hashing::IncidentCore>(
+        hashing::domain::INCIDENT_CORE,
+        incident_core_hash,
+        incident_core_payload.as_deref(),
+    )?;
+    anchor.evidence_set_hash = hashing::cross_check::<hashing::EvidenceSet>(
+        hashing::domain::EVIDENCE_SET,
+        evidence_set_hash,
+        evidence_set_payload.as_deref(),
+    )?;
+    anchor.contradictions_hash = hashing::cross_check::<hashing::Contradictions>(
+        hashing::domain::CONTRADICTIONS,
+        contradictions_hash,
+        contradictions_payload.as_deref(),
+    )?;
+    anchor.trust_receipt_hash = hashing::cross_check::<hashing::TrustReceipt>(
+        hashing::domain::TRUST_RECEIPT,
+        trust_receipt_hash,
+        trust_receipt_payload.as_deref(),
+    )?;
+    anchor.operator_decisions_hash = hashing::cross_check::<hashing::OperatorDecisions>(
+        hashing::domain::OPERATOR_DECISIONS,
+        operator_decisions_hash,
+        operator_decisions_payload.as_deref(),
+    )?;
+    anchor.timeline_hash = hashing::cross_check::<hashing::Timeline>(
+        hashing::domain::TIMELINE,
+        timeline_hash,
+        timeline_payload.as_deref(),
+    )?;
+    anchor.peaks = [[0u8; 32]; IncidentAnchor::MAX_MMR_PEAKS];
+    anchor.peak_count = 0;
+    anchor.event_count = 0;
+    let initial_event_hash = hashing::cross_check::<hashing::EventRecord>(
+        hashing::domain::EVENT,
+        initial_event_hash,
+        initial_event_payload.as_deref(),
+    )?;
+    anchor.event_chain_head = anchor.append_mmr_leaf(initial_event_hash);
     anchor.event_count = 1;
-    anchor.operator_role = operator_role;
-    anchor.supervisor = None;
-    anchor.requires_approval = operator_role == 0; // Employees need approval
+    anchor.operator_role = operator_role as u8;
+
+    let mut committee = [Pubkey::default(); IncidentAnchor::MAX_APPROVERS];
+    committee[..approvers.len()].copy_from_slice(&approvers);
+    anchor.approvers = committee;
+    anchor.approver_count = approvers.len() as u8;
+    anchor.required_approvals = required_approvals;
+    anchor.approval_bitmask = 0;
+    anchor.approval_window_seconds = approval_window_seconds;
+
+    anchor.requires_approval = operator_role == OperatorRole::Employee;
     anchor.approval_timestamp = None;
     anchor.packet_uri = packet_uri.clone();
     anchor.created_at = clock.unix_timestamp;
@@ -76,7 +150,7 @@ pub fn handler(
     emit!(AnchorCreated {
         incident_id,
         operator: ctx.accounts.operator.key(),
-        operator_role,
+        operator_role: operator_role as u8,
         bundle_root_hash: anchor.bundle_root_hash,
         packet_uri,
         timestamp: clock.unix_timestamp,
@@ -2,8 +2,16 @@ pub mod create_anchor;
 pub mod append_event;
 pub mod update_artifacts;
 pub mod approve_anchor;
+pub mod verify_artifact_inclusion;
+pub mod register_operator;
+pub mod set_operator_role;
+pub mod verify_event_membership;
 
 pub use create_anchor::*;
 pub use append_event::*;
 pub use update_artifacts::*;
 pub use approve_anchor::*;
+pub use verify_artifact_inclusion::*;
+pub use register_operator::*;
+pub use set_operator_role::*;
+pub use verify_event_membership::*;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::{OperatorRegistry, OperatorRole};
+use crate::errors::SatorAnchorError;
+use crate::ADMIN_PUBKEY;
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct RegisterOperator<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = OperatorRegistry::SPACE,
+        seeds = [b"operator", operator.as_ref()],
+        bump
+    )]
+    pub registry: Account<'info, OperatorRegistry>,
+
+    #[account(mut, address = ADMIN_PUBKEY @ SatorAnchorError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterOperator>, operator: Pubkey, role: u8) -> Result<()> {
+    require!(role <= 2, SatorAnchorError::InvalidOperatorRole);
+
+    let registry = &mut ctx.accounts.registry;
+    registry.operator = operator;
+    registry.role = OperatorRole::from(role);
+    registry.active = true;
+    registry.bump = ctx.bumps.registry;
+
+    msg!("Registered operator {:?} with role {}", operator, role);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{OperatorRegistry, OperatorRole};
+use crate::errors::SatorAnchorError;
+use crate::ADMIN_PUBKEY;
+
+#[derive(Accounts)]
+pub struct SetOperatorRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"operator", registry.operator.as_ref()],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, OperatorRegistry>,
+
+    #[account(address = ADMIN_PUBKEY @ SatorAnchorError::Unauthorized)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetOperatorRole>, role: u8, active: bool) -> Result<()> {
+    require!(role <= 2, SatorAnchorError::InvalidOperatorRole);
+
+    let registry = &mut ctx.accounts.registry;
+    registry.role = OperatorRole::from(role);
+    registry.active = active;
+
+    msg!(
+        "Updated operator {:?} to role {} (active: {})",
+        registry.operator,
+        role,
+        active
+    );
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::IncidentAnchor;
+use crate::state::{IncidentAnchor, OperatorRegistry};
 use crate::errors::SatorAnchorError;
 use crate::events::ArtifactsUpdated;
+use crate::hashing;
 
 #[derive(Accounts)]
 pub struct UpdateArtifacts<'info> {
@@ -12,7 +13,14 @@ pub struct UpdateArtifacts<'info> {
         has_one = operator @ SatorAnchorError::Unauthorized
     )]
     pub anchor: Account<'info, IncidentAnchor>,
-    
+
+    #[account(
+        seeds = [b"operator", operator.key().as_ref()],
+        bump = operator_registry.bump,
+        has_one = operator @ SatorAnchorError::OperatorRegistryMismatch,
+    )]
+    pub operator_registry: Account<'info, OperatorRegistry>,
+
     pub operator: Signer<'info>,
 }
 
@@ -25,11 +33,23 @@ pub fn handler(
     operator_decisions_hash: Option<[u8; 32]>,
     timeline_hash: Option<[u8; 32]>,
     change_event_hash: [u8; 32],
+    incident_core_payload: Option<Vec<u8>>,
+    evidence_set_payload: Option<Vec<u8>>,
+    contradictions_payload: Option<Vec<u8>>,
+    trust_receipt_payload: Option<Vec<u8>>,
+    operator_decisions_payload: Option<Vec<u8>>,
+    timeline_payload: Option<Vec<u8>>,
+    change_event_payload: Option<Vec<u8>>,
     packet_uri: Option<String>,
 ) -> Result<()> {
+    require!(
+        ctx.accounts.operator_registry.active,
+        SatorAnchorError::OperatorNotActive
+    );
+
     let anchor = &mut ctx.accounts.anchor;
     let clock = Clock::get()?;
-    
+
     // Validate URI if provided
     if let Some(ref uri) = packet_uri {
         require!(
@@ -40,24 +60,51 @@ pub fn handler(
     
     let old_bundle_root = anchor.bundle_root_hash;
     
-    // Update hashes if provided
-    if let Some(hash) = incident_core_hash {
-        anchor.incident_core_hash = hash;
+    // Update hashes if provided. When the backend also sends the canonical payload,
+    // cross-check the claimed hash against a fresh domain-separated recomputation;
+    // otherwise (large artifacts that don't fit in one transaction) the claimed hash
+    // is trusted as-is, unchanged, so the stored commitment stays transport-independent.
+    if let Some(claimed) = incident_core_hash {
+        anchor.incident_core_hash = hashing::cross_check::<hashing::IncidentCore>(
+            hashing::domain::INCIDENT_CORE,
+            claimed,
+            incident_core_payload.as_deref(),
+        )?;
     }
-    if let Some(hash) = evidence_set_hash {
-        anchor.evidence_set_hash = hash;
+    if let Some(claimed) = evidence_set_hash {
+        anchor.evidence_set_hash = hashing::cross_check::<hashing::EvidenceSet>(
+            hashing::domain::EVIDENCE_SET,
+            claimed,
+            evidence_set_payload.as_deref(),
+        )?;
     }
-    if let Some(hash) = contradictions_hash {
-        anchor.contradictions_hash = hash;
+    if let Some(claimed) = contradictions_hash {
+        anchor.contradictions_hash = hashing::cross_check::<hashing::Contradictions>(
+            hashing::domain::CONTRADICTIONS,
+            claimed,
+            contradictions_payload.as_deref(),
+        )?;
     }
-    if let Some(hash) = trust_receipt_hash {
-        anchor.trust_receipt_hash = hash;
+    if let Some(claimed) = trust_receipt_hash {
+        anchor.trust_receipt_hash = hashing::cross_check::<hashing::TrustReceipt>(
+            hashing::domain::TRUST_RECEIPT,
+            claimed,
+            trust_receipt_payload.as_deref(),
+        )?;
     }
-    if let Some(hash) = operator_decisions_hash {
-        anchor.operator_decisions_hash = hash;
+    if let Some(claimed) = operator_decisions_hash {
+        anchor.operator_decisions_hash = hashing::cross_check::<hashing::OperatorDecisions>(
+            hashing::domain::OPERATOR_DECISIONS,
+            claimed,
+            operator_decisions_payload.as_deref(),
+        )?;
     }
-    if let Some(hash) = timeline_hash {
-        anchor.timeline_hash = hash;
+    if let Some(claimed) = timeline_hash {
+        anchor.timeline_hash = hashing::cross_check::<hashing::Timeline>(
+            hashing::domain::TIMELINE,
+            claimed,
+            timeline_payload.as_deref(),
+        )?;
     }
     if let Some(uri) = packet_uri.clone() {
         anchor.packet_uri = uri;
@@ -66,12 +113,18 @@ pub fn handler(
     // Recompute bundle root
     anchor.bundle_root_hash = anchor.compute_bundle_root();
     
-    // Append the change event to the event chain
-    let new_head = anchor.compute_new_event_head(&change_event_hash);
-    anchor.event_chain_head = new_head;
-    anchor.event_count = anchor.event_count
+    // Append the change event to the MMR
+    let new_event_count = anchor.event_count
         .checked_add(1)
         .ok_or(SatorAnchorError::EventCountOverflow)?;
+    let change_event_hash = hashing::cross_check::<hashing::EventRecord>(
+        hashing::domain::EVENT,
+        change_event_hash,
+        change_event_payload.as_deref(),
+    )?;
+    let new_head = anchor.append_mmr_leaf(change_event_hash);
+    anchor.event_chain_head = new_head;
+    anchor.event_count = new_event_count;
     anchor.updated_at = clock.unix_timestamp;
     
     // Emit event
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{merkle_root_from_proof, IncidentAnchor};
+use crate::errors::SatorAnchorError;
+
+#[derive(Accounts)]
+pub struct VerifyArtifactInclusion<'info> {
+    #[account(
+        seeds = [b"incident_anchor", anchor.incident_id.to_le_bytes().as_ref()],
+        bump = anchor.bump,
+    )]
+    pub anchor: Account<'info, IncidentAnchor>,
+}
+
+/// Read-only: prove that a single artifact hash is committed by `bundle_root_hash`
+/// without needing the other five preimages.
+///
+/// `leaf_index` is the artifact's position among the 8 padded Merkle leaves (0-5 are
+/// the real artifacts, see `IncidentAnchor::merkle_leaves`), and `proof` is the sibling
+/// hash at each of the `MERKLE_DEPTH` levels on the path up to the root.
+pub fn handler(
+    ctx: Context<VerifyArtifactInclusion>,
+    leaf_index: u8,
+    leaf_hash: [u8; 32],
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        proof.len() == IncidentAnchor::MERKLE_DEPTH,
+        SatorAnchorError::InvalidProofLength
+    );
+    require!(
+        (leaf_index as usize) < IncidentAnchor::ARTIFACT_LEAF_COUNT,
+        SatorAnchorError::InvalidLeafIndex
+    );
+
+    let anchor = &ctx.accounts.anchor;
+    let recomputed = merkle_root_from_proof(leaf_index, leaf_hash, &proof);
+
+    require!(
+        recomputed == anchor.bundle_root_hash,
+        SatorAnchorError::InclusionProofMismatch
+    );
+
+    msg!(
+        "Artifact at leaf {} verified against bundle root {:?} for incident {}",
+        leaf_index,
+        anchor.bundle_root_hash,
+        anchor.incident_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{merkle_root_from_proof, mmr_bag_peaks, IncidentAnchor};
+use crate::errors::SatorAnchorError;
+
+#[derive(Accounts)]
+pub struct VerifyEventMembership<'info> {
+    #[account(
+        seeds = [b"incident_anchor", anchor.incident_id.to_le_bytes().as_ref()],
+        bump = anchor.bump,
+    )]
+    pub anchor: Account<'info, IncidentAnchor>,
+}
+
+/// Read-only: prove a specific appended event is committed by `event_chain_head`
+/// with an O(log n) proof instead of replaying the whole MMR.
+///
+/// `leaf_hash` is the event, `mountain_index` its bit-indexed position within its own
+/// mountain, and `mountain_proof` the sibling hashes up to that mountain's peak (see
+/// `merkle_root_from_proof`). `peak_index` is where the reconstructed peak sits among
+/// the anchor's current peaks, and `sibling_peaks` are the remaining peaks in their
+/// original left-to-right order.
+pub fn handler(
+    ctx: Context<VerifyEventMembership>,
+    leaf_hash: [u8; 32],
+    mountain_index: u8,
+    mountain_proof: Vec<[u8; 32]>,
+    peak_index: u8,
+    sibling_peaks: Vec<[u8; 32]>,
+) -> Result<()> {
+    let anchor = &ctx.accounts.anchor;
+
+    require!(
+        sibling_peaks.len() + 1 == anchor.peak_count as usize,
+        SatorAnchorError::InvalidMmrProof
+    );
+    require!(
+        (peak_index as usize) < anchor.peak_count as usize,
+        SatorAnchorError::InvalidMmrProof
+    );
+
+    let mountain_root = merkle_root_from_proof(mountain_index, leaf_hash, &mountain_proof);
+
+    let mut peaks = sibling_peaks;
+    peaks.insert(peak_index as usize, mountain_root);
+
+    let recomputed = mmr_bag_peaks(&peaks, anchor.peak_count);
+
+    require!(
+        recomputed == anchor.event_chain_head,
+        SatorAnchorError::EventMembershipProofMismatch
+    );
+
+    msg!(
+        "Event {:?} verified against chain head {:?} for incident {}",
+        leaf_hash,
+        anchor.event_chain_head,
+        anchor.incident_id
+    );
+
+    Ok(())
+}
@@ -4,17 +4,25 @@ pub mod state;
 pub mod instructions;
 pub mod errors;
 pub mod events;
+pub mod hashing;
 
 use instructions::*;
 
 declare_id!("SATRopsAnchor11111111111111111111111111111");
 
+/// Program admin authority allowed to register operators and set their roles
+pub const ADMIN_PUBKEY: Pubkey = pubkey!("SATRopsAdmin111111111111111111111111111111");
+
 #[program]
 pub mod sator_anchor {
     use super::*;
 
     /// Create a new incident anchor on-chain
-    /// Only the operator can create anchors for incidents they own
+    /// Only the operator can create anchors for incidents they own. Each artifact hash
+    /// may optionally be accompanied by its canonical payload for an on-chain cross-check;
+    /// large artifacts that don't fit in the transaction can omit the payload and supply
+    /// just the hash.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_anchor(
         ctx: Context<CreateAnchor>,
         incident_id: u64,
@@ -25,8 +33,17 @@ pub mod sator_anchor {
         operator_decisions_hash: [u8; 32],
         timeline_hash: [u8; 32],
         initial_event_hash: [u8; 32],
-        operator_role: u8,
+        incident_core_payload: Option<Vec<u8>>,
+        evidence_set_payload: Option<Vec<u8>>,
+        contradictions_payload: Option<Vec<u8>>,
+        trust_receipt_payload: Option<Vec<u8>>,
+        operator_decisions_payload: Option<Vec<u8>>,
+        timeline_payload: Option<Vec<u8>>,
+        initial_event_payload: Option<Vec<u8>>,
         packet_uri: String,
+        approvers: Vec<Pubkey>,
+        required_approvals: u8,
+        approval_window_seconds: i64,
     ) -> Result<()> {
         instructions::create_anchor::handler(
             ctx,
@@ -38,22 +55,36 @@ pub mod sator_anchor {
             operator_decisions_hash,
             timeline_hash,
             initial_event_hash,
-            operator_role,
+            incident_core_payload,
+            evidence_set_payload,
+            contradictions_payload,
+            trust_receipt_payload,
+            operator_decisions_payload,
+            timeline_payload,
+            initial_event_payload,
             packet_uri,
+            approvers,
+            required_approvals,
+            approval_window_seconds,
         )
     }
 
     /// Append an event to the event chain
-    /// Updates event_chain_head = sha256(prev_head || event_hash)
+    /// When the canonical payload is supplied, cross-checks it against `event_hash`
+    /// before advancing the Merkle Mountain Range and updating `event_chain_head`
+    /// to the new bagged root; otherwise trusts the claimed hash.
     pub fn append_event(
         ctx: Context<AppendEvent>,
         event_hash: [u8; 32],
+        event_payload: Option<Vec<u8>>,
     ) -> Result<()> {
-        instructions::append_event::handler(ctx, event_hash)
+        instructions::append_event::handler(ctx, event_hash, event_payload)
     }
 
     /// Update artifact hashes and recompute bundle root
-    /// Used when artifact is modified (e.g., new evidence, decision changes)
+    /// Used when artifact is modified (e.g., new evidence, decision changes). Each
+    /// updated hash may optionally be cross-checked against its canonical payload.
+    #[allow(clippy::too_many_arguments)]
     pub fn update_artifacts(
         ctx: Context<UpdateArtifacts>,
         incident_core_hash: Option<[u8; 32]>,
@@ -63,6 +94,13 @@ pub mod sator_anchor {
         operator_decisions_hash: Option<[u8; 32]>,
         timeline_hash: Option<[u8; 32]>,
         change_event_hash: [u8; 32],
+        incident_core_payload: Option<Vec<u8>>,
+        evidence_set_payload: Option<Vec<u8>>,
+        contradictions_payload: Option<Vec<u8>>,
+        trust_receipt_payload: Option<Vec<u8>>,
+        operator_decisions_payload: Option<Vec<u8>>,
+        timeline_payload: Option<Vec<u8>>,
+        change_event_payload: Option<Vec<u8>>,
         packet_uri: Option<String>,
     ) -> Result<()> {
         instructions::update_artifacts::handler(
@@ -74,14 +112,71 @@ pub mod sator_anchor {
             operator_decisions_hash,
             timeline_hash,
             change_event_hash,
+            incident_core_payload,
+            evidence_set_payload,
+            contradictions_payload,
+            trust_receipt_payload,
+            operator_decisions_payload,
+            timeline_payload,
+            change_event_payload,
             packet_uri,
         )
     }
 
-    /// Supervisor approves an employee's anchor
+    /// Committee member casts their approval; clears `requires_approval` once
+    /// `required_approvals` distinct committee members have signed
     pub fn approve_anchor(
         ctx: Context<ApproveAnchor>,
     ) -> Result<()> {
         instructions::approve_anchor::handler(ctx)
     }
+
+    /// Read-only: verify that a single artifact hash is included in `bundle_root_hash`
+    /// via a Merkle inclusion proof, without requiring the other artifact preimages
+    pub fn verify_artifact_inclusion(
+        ctx: Context<VerifyArtifactInclusion>,
+        leaf_index: u8,
+        leaf_hash: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::verify_artifact_inclusion::handler(ctx, leaf_index, leaf_hash, proof)
+    }
+
+    /// Admin-only: write a verified role for an operator into the on-chain registry
+    pub fn register_operator(
+        ctx: Context<RegisterOperator>,
+        operator: Pubkey,
+        role: u8,
+    ) -> Result<()> {
+        instructions::register_operator::handler(ctx, operator, role)
+    }
+
+    /// Admin-only: change an operator's verified role or active flag
+    pub fn set_operator_role(
+        ctx: Context<SetOperatorRole>,
+        role: u8,
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_operator_role::handler(ctx, role, active)
+    }
+
+    /// Read-only: verify that a specific appended event is committed by
+    /// `event_chain_head` via an MMR membership proof
+    pub fn verify_event_membership(
+        ctx: Context<VerifyEventMembership>,
+        leaf_hash: [u8; 32],
+        mountain_index: u8,
+        mountain_proof: Vec<[u8; 32]>,
+        peak_index: u8,
+        sibling_peaks: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::verify_event_membership::handler(
+            ctx,
+            leaf_hash,
+            mountain_index,
+            mountain_proof,
+            peak_index,
+            sibling_peaks,
+        )
+    }
 }
@@ -51,25 +51,45 @@ pub struct IncidentAnchor {
     /// This is the master commitment to the entire artifact
     pub bundle_root_hash: [u8; 32],
     
-    /// Rolling hash chain for event integrity
-    /// Updated as: new_head = SHA-256(prev_head || event_hash)
+    /// Merkle Mountain Range accumulator over all appended events: the current
+    /// peaks bagged together. Lets any past event be proven with an O(log n) proof
+    /// instead of replaying the whole chain.
     pub event_chain_head: [u8; 32],
-    
+
+    /// MMR peak hashes, left (tallest) to right (shortest); only the first
+    /// `peak_count` entries are meaningful
+    pub peaks: [[u8; 32]; Self::MAX_MMR_PEAKS],
+
+    /// Number of populated entries in `peaks`
+    pub peak_count: u8,
+
     /// Number of events appended to the chain
     pub event_count: u32,
-    
+
     /// Operator role (0=employee, 1=supervisor, 2=admin)
     pub operator_role: u8,
-    
-    /// Supervisor who approved this anchor (if employee created)
-    pub supervisor: Option<Pubkey>,
-    
-    /// Whether this anchor requires supervisor approval
+
+    /// Approval committee for this anchor, padded with `Pubkey::default()` past `approver_count`
+    pub approvers: [Pubkey; Self::MAX_APPROVERS],
+
+    /// Number of committee members actually populated in `approvers`
+    pub approver_count: u8,
+
+    /// Number of distinct committee approvals required to clear `requires_approval`
+    pub required_approvals: u8,
+
+    /// Bitmask over `approvers`; bit `i` is set once `approvers[i]` has approved
+    pub approval_bitmask: u64,
+
+    /// Approvals are only accepted while `now - created_at <= approval_window_seconds`
+    pub approval_window_seconds: i64,
+
+    /// Whether this anchor requires committee approval
     pub requires_approval: bool,
-    
-    /// Timestamp when approved by supervisor
+
+    /// Timestamp when the required approval threshold was reached
     pub approval_timestamp: Option<i64>,
-    
+
     /// URI to the full artifact packet (MongoDB doc ID or IPFS CID)
     pub packet_uri: String,
     
@@ -87,7 +107,14 @@ impl IncidentAnchor {
     /// Calculate space needed for the account
     /// Fixed fields + string with max length
     pub const MAX_URI_LEN: usize = 200;
-    
+
+    /// Maximum size of the approval committee
+    pub const MAX_APPROVERS: usize = 8;
+
+    /// Maximum number of MMR peaks; 32 suffices since `event_count` is a `u32`
+    /// (a u32 has at most 32 set bits, i.e. at most 32 peaks)
+    pub const MAX_MMR_PEAKS: usize = 32;
+
     pub const SPACE: usize = 8 +  // discriminator
         32 +                       // operator
         8 +                        // incident_id
@@ -99,9 +126,15 @@ impl IncidentAnchor {
         32 +                       // timeline_hash
         32 +                       // bundle_root_hash
         32 +                       // event_chain_head
+        32 * Self::MAX_MMR_PEAKS + // peaks
+        1 +                        // peak_count
         4 +                        // event_count
         1 +                        // operator_role
-        1 + 32 +                   // supervisor (Option<Pubkey>)
+        32 * Self::MAX_APPROVERS + // approvers
+        1 +                        // approver_count
+        1 +                        // required_approvals
+        8 +                        // approval_bitmask
+        8 +                        // approval_window_seconds
         1 +                        // requires_approval
         1 + 8 +                    // approval_timestamp (Option<i64>)
         4 + Self::MAX_URI_LEN +    // packet_uri (String)
@@ -109,29 +142,146 @@ impl IncidentAnchor {
         8 +                        // updated_at
         1;                         // bump
     
-    /// Compute the bundle root hash from all artifact hashes
+    /// Number of real artifact leaves in the bundle Merkle tree, before padding
+    pub const ARTIFACT_LEAF_COUNT: usize = 6;
+
+    /// Number of leaves in the bundle Merkle tree (6 artifacts padded to a power of two)
+    pub const MERKLE_LEAF_COUNT: usize = 8;
+
+    /// Depth of the bundle Merkle tree, i.e. the number of siblings in an inclusion proof
+    pub const MERKLE_DEPTH: usize = 3;
+
+    /// The ordered, padded leaves of the bundle Merkle tree
+    pub fn merkle_leaves(&self) -> [[u8; 32]; Self::MERKLE_LEAF_COUNT] {
+        [
+            self.incident_core_hash,
+            self.evidence_set_hash,
+            self.contradictions_hash,
+            self.trust_receipt_hash,
+            self.operator_decisions_hash,
+            self.timeline_hash,
+            [0u8; 32],
+            [0u8; 32],
+        ]
+    }
+
+    /// Compute the bundle root hash as the root of the fixed 6-leaf (padded to 8) Merkle tree
+    /// over the artifact hashes, in order
     pub fn compute_bundle_root(&self) -> [u8; 32] {
-        use anchor_lang::solana_program::hash::hash;
-        
-        let mut data = Vec::with_capacity(32 * 6);
-        data.extend_from_slice(&self.incident_core_hash);
-        data.extend_from_slice(&self.evidence_set_hash);
-        data.extend_from_slice(&self.contradictions_hash);
-        data.extend_from_slice(&self.trust_receipt_hash);
-        data.extend_from_slice(&self.operator_decisions_hash);
-        data.extend_from_slice(&self.timeline_hash);
-        
-        hash(&data).to_bytes()
+        merkle_root(self.merkle_leaves())
     }
-    
-    /// Compute new event chain head
-    pub fn compute_new_event_head(&self, event_hash: &[u8; 32]) -> [u8; 32] {
-        use anchor_lang::solana_program::hash::hash;
-        
-        let mut data = Vec::with_capacity(64);
-        data.extend_from_slice(&self.event_chain_head);
-        data.extend_from_slice(event_hash);
-        
-        hash(&data).to_bytes()
+
+    /// Append an event leaf to the MMR, updating `peaks`/`peak_count` in place, and
+    /// return the new bagged `event_chain_head`
+    pub fn append_mmr_leaf(&mut self, leaf: [u8; 32]) -> [u8; 32] {
+        let (new_peaks, new_peak_count) =
+            mmr_append(&self.peaks, self.peak_count, self.event_count, leaf);
+        self.peaks = new_peaks;
+        self.peak_count = new_peak_count;
+
+        mmr_bag_peaks(&self.peaks, self.peak_count)
     }
 }
+
+/// Combine two sibling nodes into their parent: SHA-256(left || right)
+pub fn merkle_parent(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    use anchor_lang::solana_program::hash::hash;
+
+    let mut data = Vec::with_capacity(64);
+    data.extend_from_slice(left);
+    data.extend_from_slice(right);
+
+    hash(&data).to_bytes()
+}
+
+/// Fold a fixed set of leaves bottom-up into a single Merkle root
+pub fn merkle_root(leaves: [[u8; 32]; IncidentAnchor::MERKLE_LEAF_COUNT]) -> [u8; 32] {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| merkle_parent(&pair[0], &pair[1]))
+            .collect();
+    }
+    level[0]
+}
+
+/// Recompute a Merkle root from a leaf, its index, and its sibling path.
+/// `leaf_index` is read bit-by-bit, least significant first: a 0 bit means the
+/// accumulated hash is the left child at that level, a 1 bit means it's the right child.
+pub fn merkle_root_from_proof(leaf_index: u8, leaf_hash: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+    let mut acc = leaf_hash;
+    for (depth, sibling) in proof.iter().enumerate() {
+        let bit = (leaf_index >> depth) & 1;
+        acc = if bit == 0 {
+            merkle_parent(&acc, sibling)
+        } else {
+            merkle_parent(sibling, &acc)
+        };
+    }
+    acc
+}
+
+/// Append a leaf to a Merkle Mountain Range. `peaks`/`peak_count`/`event_count` describe
+/// the MMR *before* this leaf is added. MMR peak heights are never stored explicitly:
+/// for `n` leaves already appended, the peaks correspond exactly to the set bits of `n`
+/// (tallest/leftmost peak = highest set bit), so the number of merges triggered by
+/// appending one more leaf is just the number of trailing zero bits of `n + 1`.
+pub fn mmr_append(
+    peaks: &[[u8; 32]; IncidentAnchor::MAX_MMR_PEAKS],
+    peak_count: u8,
+    event_count: u32,
+    leaf: [u8; 32],
+) -> ([[u8; 32]; IncidentAnchor::MAX_MMR_PEAKS], u8) {
+    let mut stack: Vec<[u8; 32]> = peaks[..peak_count as usize].to_vec();
+    stack.push(leaf);
+
+    let merges = (event_count + 1).trailing_zeros() as usize;
+    for _ in 0..merges {
+        let right = stack.pop().expect("mmr merge underflow");
+        let left = stack.pop().expect("mmr merge underflow");
+        stack.push(merkle_parent(&left, &right));
+    }
+
+    let mut new_peaks = [[0u8; 32]; IncidentAnchor::MAX_MMR_PEAKS];
+    new_peaks[..stack.len()].copy_from_slice(&stack);
+    (new_peaks, stack.len() as u8)
+}
+
+/// Bag a set of MMR peaks into a single accumulator hash, folding from the
+/// rightmost (shortest) peak leftward: `acc = peaks[last]`, then
+/// `acc = SHA256(peaks[i] || acc)` for `i` from `last - 1` down to `0`.
+pub fn mmr_bag_peaks(peaks: &[[u8; 32]], peak_count: u8) -> [u8; 32] {
+    let peaks = &peaks[..peak_count as usize];
+    let mut acc = peaks[peaks.len() - 1];
+    for peak in peaks[..peaks.len() - 1].iter().rev() {
+        acc = merkle_parent(peak, &acc);
+    }
+    acc
+}
+
+/// On-chain record of an operator's verified role, written only by the admin-gated
+/// `register_operator` / `set_operator_role` instructions.
+/// PDA seeds: ["operator", operator_pubkey]
+#[account]
+pub struct OperatorRegistry {
+    /// The operator this registry entry describes
+    pub operator: Pubkey,
+
+    /// The operator's verified role
+    pub role: OperatorRole,
+
+    /// Whether this operator is currently allowed to act under `role`
+    pub active: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl OperatorRegistry {
+    pub const SPACE: usize = 8 + // discriminator
+        32 +                      // operator
+        1 +                       // role
+        1 +                       // active
+        1;                        // bump
+}